@@ -2,9 +2,10 @@
 
 use std::collections::HashMap;
 use std::fs;
-use std::io::{Read, Write};
+use std::io::{BufRead, BufReader, Read, Seek, SeekFrom, Write};
 use std::net::{SocketAddr, TcpListener};
 use std::path::{Path, PathBuf};
+use std::process::{ChildStdin, Stdio};
 use std::sync::{Arc, Mutex, RwLock};
 use std::thread;
 use std::time::Duration;
@@ -12,6 +13,7 @@ use std::time::{SystemTime, UNIX_EPOCH};
 
 use anyhow::{anyhow, Context, Result};
 use mime_guess::MimeGuess;
+use notify::{RecommendedWatcher, RecursiveMode, Watcher};
 use portable_pty::{native_pty_system, CommandBuilder, PtySize};
 use serde::{Deserialize, Serialize};
 use tauri::{AppHandle, Manager, State};
@@ -59,6 +61,67 @@ fn safe_join(root: &Path, requested_path: &str) -> Result<PathBuf> {
   Ok(out)
 }
 
+/// The result of resolving a `Range` header against a file of a known length.
+enum RangeOutcome {
+  /// No usable range was requested; serve the whole file with status 200.
+  Full,
+  /// A single satisfiable range `start..=end` (inclusive); serve 206.
+  Partial(u64, u64),
+  /// No byte of the file can be served: the range starts at or beyond EOF,
+  /// the suffix length is zero, or the file is empty; serve 416.
+  Unsatisfiable,
+}
+
+/// Parse a `Range: bytes=...` header value against a file of `total` bytes.
+///
+/// Only a single byte-range-spec is supported (open-ended and suffix forms
+/// included); anything containing multiple comma-separated ranges falls back
+/// to `RangeOutcome::Full` so the caller serves the whole file.
+fn parse_range_header(value: &str, total: u64) -> RangeOutcome {
+  let Some(spec) = value.trim().strip_prefix("bytes=") else {
+    return RangeOutcome::Full;
+  };
+  if spec.contains(',') {
+    return RangeOutcome::Full;
+  }
+  let spec = spec.trim();
+  let Some((start_str, end_str)) = spec.split_once('-') else {
+    return RangeOutcome::Full;
+  };
+
+  if start_str.is_empty() {
+    // Suffix range: "-N" means the last N bytes.
+    let Ok(suffix_len) = end_str.parse::<u64>() else {
+      return RangeOutcome::Full;
+    };
+    if suffix_len == 0 || total == 0 {
+      return RangeOutcome::Unsatisfiable;
+    }
+    let start = total.saturating_sub(suffix_len);
+    return RangeOutcome::Partial(start, total - 1);
+  }
+
+  let Ok(start) = start_str.parse::<u64>() else {
+    return RangeOutcome::Full;
+  };
+  if start >= total {
+    return RangeOutcome::Unsatisfiable;
+  }
+  if end_str.is_empty() {
+    return RangeOutcome::Partial(start, total - 1);
+  }
+  let Ok(end) = end_str.parse::<u64>() else {
+    return RangeOutcome::Full;
+  };
+  let end = end.min(total - 1);
+  if end < start {
+    // Reversed range (e.g. "bytes=500-400"): not a valid byte-range-spec
+    // per RFC 7233 — fall back to serving the whole file.
+    return RangeOutcome::Full;
+  }
+  RangeOutcome::Partial(start, end)
+}
+
 fn start_local_server(roots: Arc<RwLock<WebRoots>>) -> Result<WebServerState> {
   let listener = TcpListener::bind("127.0.0.1:0").context("bind local http server")?;
   let addr = listener.local_addr().context("read local http addr")?;
@@ -151,8 +214,120 @@ fn start_local_server(roots: Arc<RwLock<WebRoots>>) -> Result<WebServerState> {
       let mime = MimeGuess::from_path(&resolved).first_or_octet_stream();
       let content_type = Some(mime.essence_str().to_string());
 
+      let send_file_response = |request: tiny_http::Request,
+                                status: u16,
+                                content_type: Option<String>,
+                                extra_headers: Vec<(&str, String)>,
+                                body: Option<Vec<u8>>| {
+        let mut response = match body {
+          Some(bytes) => tiny_http::Response::from_data(bytes)
+            .with_status_code(tiny_http::StatusCode(status))
+            .boxed(),
+          None => tiny_http::Response::empty(tiny_http::StatusCode(status)).boxed(),
+        };
+        if let Some(ct) = content_type {
+          let header =
+            tiny_http::Header::from_bytes(&b"Content-Type"[..], ct.as_bytes())
+              .expect("content-type header");
+          response = response.with_header(header).boxed();
+        }
+        for (name, value) in extra_headers {
+          let header = tiny_http::Header::from_bytes(name.as_bytes(), value.as_bytes())
+            .expect("response header");
+          response = response.with_header(header).boxed();
+        }
+        response = response
+          .with_header(
+            tiny_http::Header::from_bytes(&b"Accept-Ranges"[..], &b"bytes"[..])
+              .expect("accept-ranges header"),
+          )
+          .boxed();
+        response = response
+          .with_header(
+            tiny_http::Header::from_bytes(&b"Cache-Control"[..], &b"no-store"[..])
+              .expect("cache header"),
+          )
+          .boxed();
+        let _ = request.respond(response);
+      };
+
+      let total_len = match resolved.metadata() {
+        Ok(m) => m.len(),
+        Err(_) => {
+          send_response(
+            request,
+            500,
+            Some("text/plain".into()),
+            Some(b"Failed to stat file".to_vec()),
+          );
+          continue;
+        }
+      };
+
+      let range_header = request
+        .headers()
+        .iter()
+        .find(|h| h.field.equiv("Range"))
+        .map(|h| h.value.as_str().to_string());
+      let outcome = range_header
+        .as_deref()
+        .map(|v| parse_range_header(v, total_len))
+        .unwrap_or(RangeOutcome::Full);
+
+      if let RangeOutcome::Unsatisfiable = outcome {
+        send_file_response(
+          request,
+          416,
+          Some("text/plain".into()),
+          vec![("Content-Range", format!("bytes */{}", total_len))],
+          Some(b"Range Not Satisfiable".to_vec()),
+        );
+        continue;
+      }
+
       if method == "HEAD" {
-        send_response(request, 200, content_type, None);
+        send_file_response(request, 200, content_type, Vec::new(), None);
+        continue;
+      }
+
+      if let RangeOutcome::Partial(start, end) = outcome {
+        // `parse_range_header` only ever returns `Partial` with `end >= start`
+        // (a reversed range is normalized to `Full` there), so there's no
+        // need to re-check that invariant here.
+        let mut file = match fs::File::open(&resolved) {
+          Ok(f) => f,
+          Err(_) => {
+            send_response(
+              request,
+              500,
+              Some("text/plain".into()),
+              Some(b"Failed to read file".to_vec()),
+            );
+            continue;
+          }
+        };
+        let len = (end - start + 1) as usize;
+        let mut slice = vec![0u8; len];
+        let read_ok = file
+          .seek(SeekFrom::Start(start))
+          .and_then(|_| file.read_exact(&mut slice))
+          .is_ok();
+        if !read_ok {
+          send_response(
+            request,
+            500,
+            Some("text/plain".into()),
+            Some(b"Failed to read file".to_vec()),
+          );
+          continue;
+        }
+        send_file_response(
+          request,
+          206,
+          content_type,
+          vec![("Content-Range", format!("bytes {}-{}/{}", start, end, total_len))],
+          Some(slice),
+        );
         continue;
       }
 
@@ -168,7 +343,7 @@ fn start_local_server(roots: Arc<RwLock<WebRoots>>) -> Result<WebServerState> {
           continue;
         }
       };
-      send_response(request, 200, content_type, Some(bytes));
+      send_file_response(request, 200, content_type, Vec::new(), Some(bytes));
     }
   });
 
@@ -391,6 +566,287 @@ fn run_artifacts(params: RunArtifactsParams) -> CommandResult<Vec<ArtifactInfo>>
   Ok(artifacts)
 }
 
+// ---------------------------------------------
+// Filesystem watcher (live run/artifact events)
+// ---------------------------------------------
+
+/// Holds the live `notify` watcher for one project's runs directory. Kept
+/// alive only by the `WatcherState` registry; dropping it (on `unwatch_project`)
+/// tears down the underlying OS watch and its debounce thread.
+struct ProjectWatcher {
+  _watcher: RecommendedWatcher,
+}
+
+struct WatcherState {
+  watchers: Mutex<HashMap<String, ProjectWatcher>>,
+}
+
+/// Start watching `project_root`'s runs directory and debounce raw fs events
+/// into `runs_changed` / `run_artifacts_changed` app events.
+///
+/// Events are coalesced over a ~200ms window: any activity schedules a single
+/// `runs_changed` emission, plus one `run_artifacts_changed` per run_id that
+/// saw activity, once ~200ms have passed since the first event of the burst
+/// (further events in that window do not push the flush back).
+fn watch_runs_dir(app: AppHandle, project_root: String) -> Result<RecommendedWatcher> {
+  let runs_dir = runs_dir_for_project(&project_root);
+  fs::create_dir_all(&runs_dir).context("create runs dir")?;
+
+  let (tx, rx) = std::sync::mpsc::channel::<notify::Event>();
+  let mut watcher = notify::recommended_watcher(move |res: notify::Result<notify::Event>| {
+    if let Ok(event) = res {
+      let _ = tx.send(event);
+    }
+  })
+  .context("create fs watcher")?;
+  watcher
+    .watch(&runs_dir, RecursiveMode::Recursive)
+    .context("watch runs dir")?;
+
+  thread::spawn(move || {
+    // Flush at most this long after the *first* pending event in a burst,
+    // rather than waiting for a quiet gap — a job that streams output
+    // continuously must still produce periodic updates.
+    const FLUSH_WINDOW: Duration = Duration::from_millis(200);
+
+    let mut dirty_runs: std::collections::HashSet<String> = std::collections::HashSet::new();
+    let mut first_pending: Option<std::time::Instant> = None;
+    loop {
+      let timeout = match first_pending {
+        Some(started) => FLUSH_WINDOW.saturating_sub(started.elapsed()),
+        None => Duration::from_secs(3600),
+      };
+      match rx.recv_timeout(timeout) {
+        Ok(event) => {
+          if first_pending.is_none() {
+            first_pending = Some(std::time::Instant::now());
+          }
+          for path in event.paths {
+            if let Ok(rel) = path.strip_prefix(&runs_dir) {
+              if let Some(run_id) = rel.components().next().and_then(|c| c.as_os_str().to_str()) {
+                dirty_runs.insert(run_id.to_string());
+              }
+            }
+          }
+        }
+        Err(std::sync::mpsc::RecvTimeoutError::Timeout) => {
+          if first_pending.is_some() {
+            let _ = app.emit_all(
+              "runs_changed",
+              serde_json::json!({ "project_root": project_root }),
+            );
+            for run_id in dirty_runs.drain() {
+              let _ = app.emit_all("run_artifacts_changed", serde_json::json!({ "run_id": run_id }));
+            }
+            first_pending = None;
+          }
+        }
+        Err(std::sync::mpsc::RecvTimeoutError::Disconnected) => break,
+      }
+    }
+  });
+
+  Ok(watcher)
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct WatchProjectParams {
+  project_root: String,
+}
+
+#[tauri::command]
+fn watch_project(
+  app: AppHandle,
+  state: State<'_, Arc<WatcherState>>,
+  params: WatchProjectParams,
+) -> CommandResult<()> {
+  let mut watchers = state
+    .watchers
+    .lock()
+    .map_err(|_| "watcher registry lock poisoned".to_string())?;
+  if watchers.contains_key(&params.project_root) {
+    return Ok(());
+  }
+  let watcher = watch_runs_dir(app, params.project_root.clone()).map_err(|e| e.to_string())?;
+  watchers.insert(params.project_root, ProjectWatcher { _watcher: watcher });
+  Ok(())
+}
+
+#[tauri::command]
+fn unwatch_project(
+  state: State<'_, Arc<WatcherState>>,
+  params: WatchProjectParams,
+) -> CommandResult<()> {
+  let mut watchers = state
+    .watchers
+    .lock()
+    .map_err(|_| "watcher registry lock poisoned".to_string())?;
+  watchers.remove(&params.project_root);
+  Ok(())
+}
+
+// ---------------------------------------------
+// Execution transport (local vs. remote-over-SSH)
+// ---------------------------------------------
+
+/// Where a job or PTY session's shell actually runs. `Local` preserves the
+/// existing `native_pty_system()` behavior; `Ssh` drives the same shell
+/// inside a remote PTY over `ssh -tt`, so output/exit events keep the same
+/// shape regardless of where the command executes.
+#[derive(Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase", tag = "kind")]
+enum Transport {
+  #[default]
+  Local,
+  Ssh {
+    host: String,
+    #[serde(default)]
+    user: Option<String>,
+    #[serde(default)]
+    port: Option<u16>,
+    #[serde(default)]
+    identity: Option<String>,
+    /// The project root's path *on the remote host*. The desktop's
+    /// `.glia-fab/runs` layout is mirrored under this path remotely; it is
+    /// never assumed to equal the local `project_root`, since the two hosts
+    /// need not share a filesystem layout.
+    remote_root: String,
+  },
+}
+
+fn ssh_target(user: &Option<String>, host: &str) -> String {
+  match user {
+    Some(user) => format!("{}@{}", user, host),
+    None => host.to_string(),
+  }
+}
+
+/// The `.glia-fab/runs/<run_id>` directory for a job on the remote host,
+/// mirroring `runs_dir_for_project` but rooted at `remote_root` instead of
+/// the local project root.
+fn remote_run_dir(remote_root: &str, run_id: &str) -> String {
+  format!("{}/.glia-fab/runs/{}", remote_root.trim_end_matches('/'), run_id)
+}
+
+fn ssh_base_args(port: &Option<u16>, identity: &Option<String>) -> Vec<String> {
+  let mut args = Vec::new();
+  if let Some(port) = port {
+    args.push("-p".to_string());
+    args.push(port.to_string());
+  }
+  if let Some(identity) = identity {
+    args.push("-i".to_string());
+    args.push(identity.clone());
+  }
+  args
+}
+
+/// Single-quote `s` for embedding in a remote shell command line.
+fn shell_escape(s: &str) -> String {
+  format!("'{}'", s.replace('\'', "'\\''"))
+}
+
+/// Build a `CommandBuilder` that runs `zsh shell_args` with `env` set, either
+/// as a local child process or, for `Transport::Ssh`, as the command line of
+/// an `ssh -tt` session into the remote host. `cwd` becomes a `cd` prefix on
+/// the remote command since ssh has no concept of a remote working directory.
+/// `remote_setup`, when set, is run before `cd`/`env` on the remote host only
+/// (e.g. `mkdir -p` the run directory the command is about to write into);
+/// it is ignored for `Transport::Local`, which sets up local state itself.
+fn spawn_shell_command(
+  transport: &Transport,
+  cwd: Option<&str>,
+  remote_setup: Option<&str>,
+  shell_args: &[&str],
+  env: &[(&str, &str)],
+) -> CommandBuilder {
+  match transport {
+    Transport::Local => {
+      let mut cmd = CommandBuilder::new("zsh");
+      cmd.args(shell_args);
+      if let Some(cwd) = cwd {
+        cmd.cwd(cwd);
+      }
+      for (k, v) in env {
+        cmd.env(*k, *v);
+      }
+      cmd
+    }
+    Transport::Ssh { host, user, port, identity, .. } => {
+      let mut cmd = CommandBuilder::new("ssh");
+      cmd.arg("-tt");
+      for arg in ssh_base_args(port, identity) {
+        cmd.arg(arg);
+      }
+      cmd.arg(ssh_target(user, host));
+
+      let mut remote_cmd = String::new();
+      if let Some(setup) = remote_setup {
+        remote_cmd.push_str(setup);
+        remote_cmd.push_str(" && ");
+      }
+      if let Some(cwd) = cwd {
+        remote_cmd.push_str(&format!("cd {} && ", shell_escape(cwd)));
+      }
+      remote_cmd.push_str("env");
+      for (k, v) in env {
+        remote_cmd.push_str(&format!(" {}={}", k, shell_escape(v)));
+      }
+      remote_cmd.push_str(" zsh");
+      for arg in shell_args {
+        remote_cmd.push(' ');
+        remote_cmd.push_str(&shell_escape(arg));
+      }
+      cmd.arg(remote_cmd);
+      cmd
+    }
+  }
+}
+
+/// `rsync` the job's *remote* run directory back into its local counterpart
+/// (which may live at an entirely different path) so `run_artifacts` and the
+/// `/artifacts` server routes keep working unchanged for jobs that ran on a
+/// remote host. No-op (`Ok`) for `Local`. Returns the failure reason instead
+/// of swallowing it, so callers can log/report a sync that didn't happen.
+fn sync_remote_run_dir(
+  transport: &Transport,
+  remote_run_dir: &str,
+  local_run_dir: &Path,
+) -> std::result::Result<(), String> {
+  let Transport::Ssh { host, user, port, identity, .. } = transport else {
+    return Ok(());
+  };
+  let mut rsh = String::from("ssh");
+  for arg in ssh_base_args(port, identity) {
+    rsh.push(' ');
+    rsh.push_str(&arg);
+  }
+  let remote_spec = format!("{}:{}/", ssh_target(user, host), remote_run_dir);
+  let local_spec = format!("{}/", local_run_dir.to_string_lossy());
+  let status = std::process::Command::new("rsync")
+    .args(["-az", "-e", &rsh])
+    .arg(remote_spec)
+    .arg(local_spec)
+    .status()
+    .map_err(|e| format!("failed to spawn rsync: {}", e))?;
+  if status.success() {
+    Ok(())
+  } else {
+    Err(format!("rsync exited with {}", status))
+  }
+}
+
+/// Log and emit a `job_sync_error` event for a failed `sync_remote_run_dir`
+/// call, so a silent rsync failure doesn't look like "no artifacts produced".
+fn report_sync_failure(app: &AppHandle, job_id: &str, run_id: &str, err: &str) {
+  eprintln!("job {} ({}): remote artifact sync failed: {}", job_id, run_id, err);
+  let _ = app.emit_all(
+    "job_sync_error",
+    serde_json::json!({ "job_id": job_id, "run_id": run_id, "error": err }),
+  );
+}
+
 // ---------------------------------------------
 // Job runner (one-shot commands writing into a run dir)
 // ---------------------------------------------
@@ -404,6 +860,8 @@ struct JobStartParams {
   label: Option<String>,
   #[serde(default)]
   env: Option<HashMap<String, String>>,
+  #[serde(default)]
+  transport: Transport,
 }
 
 #[derive(Serialize)]
@@ -414,8 +872,31 @@ struct JobInfo {
   run_dir: String,
 }
 
+/// A live, registered job process. Mirrors `PtySession`: the registry holds
+/// an `Arc` so both the exit-watcher thread and `job_kill`/`job_status`
+/// commands can reach the same child handle.
+struct JobHandle {
+  run_id: String,
+  run_dir: PathBuf,
+  label: Option<String>,
+  started_ms: u64,
+  child: Mutex<Box<dyn portable_pty::Child + Send>>,
+}
+
+struct JobState {
+  jobs: Mutex<HashMap<Uuid, Arc<JobHandle>>>,
+  /// Run directory for every job this process has started, kept around after
+  /// the job exits (and its `JobHandle` is removed) so `job_status` can still
+  /// locate `job_result.json` for a finished run.
+  run_dirs: Mutex<HashMap<Uuid, PathBuf>>,
+}
+
 #[tauri::command]
-fn job_start(app: AppHandle, params: JobStartParams) -> CommandResult<JobInfo> {
+fn job_start(
+  app: AppHandle,
+  state: State<'_, Arc<JobState>>,
+  params: JobStartParams,
+) -> CommandResult<JobInfo> {
   let job_id = Uuid::new_v4();
   let now_ms = epoch_ms_now();
   let slug = params
@@ -478,16 +959,41 @@ fn job_start(app: AppHandle, params: JobStartParams) -> CommandResult<JobInfo> {
     })
     .map_err(|e| e.to_string())?;
 
-  let mut cmd = CommandBuilder::new("zsh");
-  cmd.args(["-lc", &params.command]);
-  cmd.cwd(&params.project_root);
-  cmd.env("GLIA_FAB_RUN_ID", &run_id);
-  cmd.env("GLIA_FAB_RUN_DIR", run_dir_str.clone());
+  // For a remote transport, the shell must `cd` into (and see
+  // GLIA_FAB_RUN_DIR pointed at) the remote host's own paths — the local
+  // `project_root`/`run_dir` only exist on this desktop.
+  let (exec_cwd, remote_run_dir_value) = match &params.transport {
+    Transport::Local => (params.project_root.clone(), None),
+    Transport::Ssh { remote_root, .. } => {
+      (remote_root.clone(), Some(remote_run_dir(remote_root, &run_id)))
+    }
+  };
+  let run_dir_env = remote_run_dir_value.clone().unwrap_or_else(|| run_dir_str.clone());
+
+  // The remote run dir has to exist before the job can write artifacts into
+  // it (and before any rsync back reads from it) — `fs::create_dir_all`
+  // above only created the local one.
+  let remote_mkdir = remote_run_dir_value
+    .as_deref()
+    .map(|dir| format!("mkdir -p {}", shell_escape(dir)));
+
+  let mut env_vars: Vec<(String, String)> = vec![
+    ("GLIA_FAB_RUN_ID".to_string(), run_id.clone()),
+    ("GLIA_FAB_RUN_DIR".to_string(), run_dir_env),
+  ];
   if let Some(env) = &params.env {
     for (k, v) in env {
-      cmd.env(k, v);
+      env_vars.push((k.clone(), v.clone()));
     }
   }
+  let env_refs: Vec<(&str, &str)> = env_vars.iter().map(|(k, v)| (k.as_str(), v.as_str())).collect();
+  let cmd = spawn_shell_command(
+    &params.transport,
+    Some(&exec_cwd),
+    remote_mkdir.as_deref(),
+    &["-lc", &params.command],
+    &env_refs,
+  );
 
   let child = pair
     .slave
@@ -500,6 +1006,28 @@ fn job_start(app: AppHandle, params: JobStartParams) -> CommandResult<JobInfo> {
     .try_clone_reader()
     .map_err(|e| e.to_string())?;
 
+  let handle = Arc::new(JobHandle {
+    run_id: run_id.clone(),
+    run_dir: run_dir.clone(),
+    label: params.label.clone(),
+    started_ms: now_ms,
+    child: Mutex::new(child),
+  });
+  {
+    let mut jobs = state
+      .jobs
+      .lock()
+      .map_err(|_| "job registry lock poisoned".to_string())?;
+    jobs.insert(job_id, handle.clone());
+  }
+  {
+    let mut run_dirs = state
+      .run_dirs
+      .lock()
+      .map_err(|_| "job run_dirs lock poisoned".to_string())?;
+    run_dirs.insert(job_id, run_dir.clone());
+  }
+
   let job_id_str = job_id.to_string();
   let job_id_for_output = job_id_str.clone();
   let job_id_for_exit = job_id_str.clone();
@@ -535,20 +1063,74 @@ fn job_start(app: AppHandle, params: JobStartParams) -> CommandResult<JobInfo> {
     }
   });
 
+  // Remote jobs write artifacts on the remote host as they run, so a single
+  // sync at exit would leave the live-watch/artifact-list flow (chunk0-2,
+  // chunk0-4) seeing nothing until the job finishes. Sync periodically too.
+  if let Some(remote_run_dir_for_poll) = remote_run_dir_value.clone() {
+    let transport_for_poll = params.transport.clone();
+    let run_dir_for_poll = run_dir.clone();
+    let state_for_poll = state.inner().clone();
+    let app_for_poll = app.clone();
+    let job_id_for_poll = job_id;
+    let job_id_str_for_poll = job_id_str.clone();
+    let run_id_for_poll = run_id.clone();
+    thread::spawn(move || {
+      const SYNC_INTERVAL: Duration = Duration::from_secs(2);
+      loop {
+        thread::sleep(SYNC_INTERVAL);
+        let still_running = state_for_poll
+          .jobs
+          .lock()
+          .map(|jobs| jobs.contains_key(&job_id_for_poll))
+          .unwrap_or(false);
+        if !still_running {
+          break;
+        }
+        if let Err(err) =
+          sync_remote_run_dir(&transport_for_poll, &remote_run_dir_for_poll, &run_dir_for_poll)
+        {
+          report_sync_failure(&app_for_poll, &job_id_str_for_poll, &run_id_for_poll, &err);
+        }
+      }
+    });
+  }
+
   let app_for_exit = app.clone();
   let run_id_for_exit = run_id.clone();
   let run_dir_for_exit = run_dir.clone();
+  let state_for_exit = state.inner().clone();
+  let handle_for_exit = handle;
+  let transport_for_exit = params.transport.clone();
+  let remote_run_dir_for_exit = remote_run_dir_value;
   thread::spawn(move || {
-    let mut child = child;
     let exit_code = loop {
-      match child.try_wait() {
-        Ok(Some(s)) => break Some(s.exit_code()),
-        Ok(None) => {}
-        Err(_) => break None,
+      let status = {
+        let mut child = match handle_for_exit.child.lock() {
+          Ok(c) => c,
+          Err(_) => break None,
+        };
+        match child.try_wait() {
+          Ok(Some(s)) => break Some(s.exit_code()),
+          Ok(None) => None,
+          Err(_) => break None,
+        }
+      };
+      if status.is_some() {
+        break status;
       }
       thread::sleep(Duration::from_millis(120));
     };
 
+    if let Ok(mut jobs) = state_for_exit.jobs.lock() {
+      jobs.remove(&job_id);
+    }
+
+    if let Some(remote_run_dir) = &remote_run_dir_for_exit {
+      if let Err(err) = sync_remote_run_dir(&transport_for_exit, remote_run_dir, &run_dir_for_exit) {
+        report_sync_failure(&app_for_exit, &job_id_for_exit, &run_id_for_exit, &err);
+      }
+    }
+
     let result_path = run_dir_for_exit.join("job_result.json");
     let _ = fs::write(
       &result_path,
@@ -574,10 +1156,162 @@ fn job_start(app: AppHandle, params: JobStartParams) -> CommandResult<JobInfo> {
   })
 }
 
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct JobTailParams {
+  project_root: String,
+  run_id: String,
+  #[serde(default)]
+  max_bytes: Option<u64>,
+}
+
+/// Reads back the tail of a job's `terminal.log`, so a terminal view that
+/// mounts after a job has already produced output can repaint immediately.
+#[tauri::command]
+fn job_tail(params: JobTailParams) -> CommandResult<String> {
+  let log_path = runs_dir_for_project(&params.project_root)
+    .join(&params.run_id)
+    .join("terminal.log");
+  let mut file = fs::File::open(&log_path).map_err(|e| e.to_string())?;
+  let total_len = file.metadata().map_err(|e| e.to_string())?.len();
+  let max_bytes = params.max_bytes.unwrap_or(PTY_SCROLLBACK_CAP_BYTES as u64);
+  let start = total_len.saturating_sub(max_bytes);
+  file.seek(SeekFrom::Start(start)).map_err(|e| e.to_string())?;
+  let mut buf = Vec::new();
+  file.read_to_end(&mut buf).map_err(|e| e.to_string())?;
+  Ok(String::from_utf8_lossy(&buf).to_string())
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct JobIdParams {
+  job_id: String,
+}
+
+#[tauri::command]
+fn job_kill(state: State<'_, Arc<JobState>>, params: JobIdParams) -> CommandResult<()> {
+  let id = Uuid::parse_str(&params.job_id).map_err(|e| e.to_string())?;
+  let handle = {
+    let jobs = state
+      .jobs
+      .lock()
+      .map_err(|_| "job registry lock poisoned".to_string())?;
+    jobs.get(&id).cloned()
+  };
+  let Some(handle) = handle else {
+    return Ok(());
+  };
+  let mut child = handle
+    .child
+    .lock()
+    .map_err(|_| "job child lock poisoned".to_string())?;
+  child.kill().ok();
+  Ok(())
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct RunningJobInfo {
+  job_id: String,
+  run_id: String,
+  label: Option<String>,
+  elapsed_ms: u64,
+}
+
+#[tauri::command]
+fn job_list(state: State<'_, Arc<JobState>>) -> CommandResult<Vec<RunningJobInfo>> {
+  let jobs = state
+    .jobs
+    .lock()
+    .map_err(|_| "job registry lock poisoned".to_string())?;
+  let now_ms = epoch_ms_now();
+  Ok(
+    jobs
+      .iter()
+      .map(|(id, handle)| RunningJobInfo {
+        job_id: id.to_string(),
+        run_id: handle.run_id.clone(),
+        label: handle.label.clone(),
+        elapsed_ms: now_ms.saturating_sub(handle.started_ms),
+      })
+      .collect(),
+  )
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase", tag = "state")]
+enum JobStatus {
+  Running { run_id: String, elapsed_ms: u64 },
+  Exited { run_id: String, exit_code: Option<u32> },
+  Unknown,
+}
+
+#[tauri::command]
+fn job_status(state: State<'_, Arc<JobState>>, params: JobIdParams) -> CommandResult<JobStatus> {
+  let id = Uuid::parse_str(&params.job_id).map_err(|e| e.to_string())?;
+
+  let handle = {
+    let jobs = state
+      .jobs
+      .lock()
+      .map_err(|_| "job registry lock poisoned".to_string())?;
+    jobs.get(&id).cloned()
+  };
+  if let Some(handle) = handle {
+    return Ok(JobStatus::Running {
+      run_id: handle.run_id.clone(),
+      elapsed_ms: epoch_ms_now().saturating_sub(handle.started_ms),
+    });
+  }
+
+  let run_dir = {
+    let run_dirs = state
+      .run_dirs
+      .lock()
+      .map_err(|_| "job run_dirs lock poisoned".to_string())?;
+    run_dirs.get(&id).cloned()
+  };
+  let Some(run_dir) = run_dir else {
+    return Ok(JobStatus::Unknown);
+  };
+
+  let result_path = run_dir.join("job_result.json");
+  let Ok(bytes) = fs::read(&result_path) else {
+    return Ok(JobStatus::Unknown);
+  };
+  let Ok(result): std::result::Result<serde_json::Value, _> = serde_json::from_slice(&bytes) else {
+    return Ok(JobStatus::Unknown);
+  };
+  let run_id = result
+    .get("run_id")
+    .and_then(|v| v.as_str())
+    .unwrap_or_default()
+    .to_string();
+  let exit_code = result
+    .get("exit_code")
+    .and_then(|v| v.as_u64())
+    .map(|v| v as u32);
+  Ok(JobStatus::Exited { run_id, exit_code })
+}
+
 // ---------------------------------------------
 // PTY sessions (multi-terminal)
 // ---------------------------------------------
 
+/// Cap on the raw-output scrollback kept per PTY session, so a long-lived
+/// terminal can't grow its buffer without bound.
+const PTY_SCROLLBACK_CAP_BYTES: usize = 256 * 1024;
+
+/// Append `chunk` to a bounded ring buffer, dropping the oldest bytes once
+/// the cap is exceeded.
+fn append_scrollback(buf: &mut Vec<u8>, chunk: &[u8], cap: usize) {
+  buf.extend_from_slice(chunk);
+  if buf.len() > cap {
+    let overflow = buf.len() - cap;
+    buf.drain(0..overflow);
+  }
+}
+
 #[derive(Serialize, Clone)]
 struct PtySessionInfo {
   id: String,
@@ -590,6 +1324,7 @@ struct PtySession {
   master: Mutex<Box<dyn portable_pty::MasterPty + Send>>,
   writer: Mutex<Box<dyn Write + Send>>,
   child: Mutex<Box<dyn portable_pty::Child + Send>>,
+  scrollback: Mutex<Vec<u8>>,
 }
 
 struct PtyState {
@@ -601,6 +1336,8 @@ struct PtyCreateParams {
   cwd: Option<String>,
   cols: Option<u16>,
   rows: Option<u16>,
+  #[serde(default)]
+  transport: Transport,
 }
 
 #[tauri::command]
@@ -620,11 +1357,7 @@ fn pty_create(
     .context("open pty")
     .map_err(|e| e.to_string())?;
 
-  let mut cmd = CommandBuilder::new("zsh");
-  cmd.arg("-l");
-  if let Some(cwd) = &params.cwd {
-    cmd.cwd(cwd);
-  }
+  let cmd = spawn_shell_command(&params.transport, params.cwd.as_deref(), None, &["-l"], &[]);
 
   let child = pair
     .slave
@@ -656,6 +1389,7 @@ fn pty_create(
     master: Mutex::new(master),
     writer: Mutex::new(writer),
     child: Mutex::new(child),
+    scrollback: Mutex::new(Vec::new()),
   });
 
   {
@@ -668,6 +1402,7 @@ fn pty_create(
 
   let app_for_output = app.clone();
   let session_id_for_output = session_id.clone();
+  let session_for_output = session.clone();
   thread::spawn(move || {
     let mut buf = [0u8; 8192];
     loop {
@@ -676,6 +1411,9 @@ fn pty_create(
         Ok(n) => n,
         Err(_) => break,
       };
+      if let Ok(mut scrollback) = session_for_output.scrollback.lock() {
+        append_scrollback(&mut scrollback, &buf[..read], PTY_SCROLLBACK_CAP_BYTES);
+      }
       let chunk = String::from_utf8_lossy(&buf[..read]).to_string();
       let _ = app_for_output.emit_all(
         "pty_output",
@@ -825,6 +1563,281 @@ fn pty_list(state: State<'_, Arc<PtyState>>) -> CommandResult<Vec<PtySessionInfo
   Ok(sessions.values().map(|s| s.info.clone()).collect())
 }
 
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PtySnapshotParams {
+  session_id: String,
+}
+
+/// Returns the buffered scrollback for a session so a frontend terminal that
+/// mounts late (or remounts) can repaint immediately instead of starting blank.
+#[tauri::command]
+fn pty_snapshot(state: State<'_, Arc<PtyState>>, params: PtySnapshotParams) -> CommandResult<String> {
+  let id = Uuid::parse_str(&params.session_id).map_err(|e| e.to_string())?;
+  let session = {
+    let sessions = state
+      .sessions
+      .lock()
+      .map_err(|_| "pty sessions lock poisoned".to_string())?;
+    sessions.get(&id).cloned()
+  };
+  let Some(session) = session else {
+    return Err("session not found".to_string());
+  };
+  let scrollback = session
+    .scrollback
+    .lock()
+    .map_err(|_| "pty scrollback lock poisoned".to_string())?;
+  Ok(String::from_utf8_lossy(&scrollback).to_string())
+}
+
+// ---------------------------------------------
+// LSP bridge (language server proxy for the dev-kernel workspace)
+// ---------------------------------------------
+
+/// Resolve the (program, args) to launch for a given editor language id.
+/// Unknown languages have no configured server.
+fn language_server_command(language: &str) -> Option<(&'static str, &'static [&'static str])> {
+  match language {
+    "rust" => Some(("rust-analyzer", &[])),
+    "typescript" | "javascript" | "tsx" | "jsx" => {
+      Some(("typescript-language-server", &["--stdio"]))
+    }
+    "python" => Some(("pylsp", &[])),
+    _ => None,
+  }
+}
+
+/// Read one `Content-Length`-framed JSON-RPC message from `reader`.
+/// Returns `Ok(None)` at EOF (the server process exited), and also when a
+/// header block has no `Content-Length` or the body is not valid JSON.
+fn read_lsp_message<R: BufRead>(reader: &mut R) -> std::io::Result<Option<serde_json::Value>> {
+  let mut content_length: Option<usize> = None;
+  loop {
+    let mut line = String::new();
+    if reader.read_line(&mut line)? == 0 {
+      return Ok(None);
+    }
+    let trimmed = line.trim_end();
+    if trimmed.is_empty() {
+      break;
+    }
+    if let Some(value) = trimmed.strip_prefix("Content-Length:") {
+      content_length = value.trim().parse::<usize>().ok();
+    }
+  }
+  let Some(len) = content_length else {
+    return Ok(None);
+  };
+  let mut body = vec![0u8; len];
+  reader.read_exact(&mut body)?;
+  Ok(serde_json::from_slice(&body).ok())
+}
+
+/// Point an `initialize` request's `rootUri`/`rootPath`/`workspaceFolders`
+/// at the project's detected dev-kernel workspace instead of whatever the
+/// frontend editor opened.
+fn rewrite_initialize_paths(message: &mut serde_json::Value, dev_kernel_dir: &str) {
+  let uri = format!("file://{}", dev_kernel_dir);
+  if let Some(params) = message.get_mut("params").and_then(|p| p.as_object_mut()) {
+    params.insert("rootUri".into(), serde_json::Value::String(uri.clone()));
+    params.insert("rootPath".into(), serde_json::Value::String(dev_kernel_dir.to_string()));
+    params.insert(
+      "workspaceFolders".into(),
+      serde_json::json!([{ "uri": uri, "name": "dev-kernel" }]),
+    );
+  }
+}
+
+#[derive(Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+struct LspSessionInfo {
+  id: String,
+  language: String,
+  project_root: String,
+  dev_kernel_dir: Option<String>,
+}
+
+struct LspSession {
+  info: LspSessionInfo,
+  stdin: Mutex<ChildStdin>,
+  child: Mutex<std::process::Child>,
+}
+
+struct LspState {
+  sessions: Mutex<HashMap<Uuid, Arc<LspSession>>>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct LspStartParams {
+  project_root: String,
+  language: String,
+}
+
+#[tauri::command]
+fn lsp_start(
+  app: AppHandle,
+  state: State<'_, Arc<LspState>>,
+  params: LspStartParams,
+) -> CommandResult<String> {
+  let Some((program, args)) = language_server_command(&params.language) else {
+    return Err(format!("no language server configured for {}", params.language));
+  };
+
+  let dev_kernel_dir = PathBuf::from(&params.project_root).join("dev-kernel");
+  let dev_kernel_dir = dev_kernel_dir
+    .is_dir()
+    .then(|| dev_kernel_dir.to_string_lossy().to_string());
+
+  let mut child = std::process::Command::new(program)
+    .args(args)
+    .current_dir(&params.project_root)
+    .stdin(Stdio::piped())
+    .stdout(Stdio::piped())
+    .stderr(Stdio::null())
+    .spawn()
+    .map_err(|e| e.to_string())?;
+
+  let stdin = child.stdin.take().ok_or("missing language server stdin")?;
+  let stdout = child.stdout.take().ok_or("missing language server stdout")?;
+
+  let id = Uuid::new_v4();
+  let info = LspSessionInfo {
+    id: id.to_string(),
+    language: params.language,
+    project_root: params.project_root,
+    dev_kernel_dir,
+  };
+  let session_id = info.id.clone();
+
+  let session = Arc::new(LspSession {
+    info,
+    stdin: Mutex::new(stdin),
+    child: Mutex::new(child),
+  });
+
+  {
+    let mut sessions = state
+      .sessions
+      .lock()
+      .map_err(|_| "lsp sessions lock poisoned".to_string())?;
+    sessions.insert(id, session.clone());
+  }
+
+  let app_for_output = app.clone();
+  let session_id_for_output = session_id.clone();
+  thread::spawn(move || {
+    let mut reader = BufReader::new(stdout);
+    loop {
+      match read_lsp_message(&mut reader) {
+        Ok(Some(message)) => {
+          let _ = app_for_output.emit_all(
+            "lsp_output",
+            serde_json::json!({ "session_id": session_id_for_output, "message": message }),
+          );
+        }
+        Ok(None) | Err(_) => break,
+      }
+    }
+  });
+
+  let state_for_exit = state.inner().clone();
+  thread::spawn(move || loop {
+    let exited = {
+      let mut child = match session.child.lock() {
+        Ok(c) => c,
+        Err(_) => break,
+      };
+      matches!(child.try_wait(), Ok(Some(_)) | Err(_))
+    };
+    if exited {
+      if let Ok(mut sessions) = state_for_exit.sessions.lock() {
+        sessions.remove(&id);
+      }
+      break;
+    }
+    thread::sleep(Duration::from_millis(200));
+  });
+
+  Ok(session_id)
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct LspSendParams {
+  session_id: String,
+  message: serde_json::Value,
+}
+
+#[tauri::command]
+fn lsp_send(state: State<'_, Arc<LspState>>, params: LspSendParams) -> CommandResult<()> {
+  let id = Uuid::parse_str(&params.session_id).map_err(|e| e.to_string())?;
+  let session = {
+    let sessions = state
+      .sessions
+      .lock()
+      .map_err(|_| "lsp sessions lock poisoned".to_string())?;
+    sessions.get(&id).cloned()
+  };
+  let Some(session) = session else {
+    return Err("session not found".to_string());
+  };
+
+  let mut message = params.message;
+  if message.get("method").and_then(|m| m.as_str()) == Some("initialize") {
+    if let Some(dev_kernel_dir) = &session.info.dev_kernel_dir {
+      rewrite_initialize_paths(&mut message, dev_kernel_dir);
+    }
+  }
+
+  let bytes = serde_json::to_vec(&message).map_err(|e| e.to_string())?;
+  let mut stdin = session
+    .stdin
+    .lock()
+    .map_err(|_| "lsp stdin lock poisoned".to_string())?;
+  write!(stdin, "Content-Length: {}\r\n\r\n", bytes.len()).map_err(|e| e.to_string())?;
+  stdin.write_all(&bytes).map_err(|e| e.to_string())?;
+  stdin.flush().ok();
+  Ok(())
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct LspSessionIdParams {
+  session_id: String,
+}
+
+#[tauri::command]
+fn lsp_stop(state: State<'_, Arc<LspState>>, params: LspSessionIdParams) -> CommandResult<()> {
+  let id = Uuid::parse_str(&params.session_id).map_err(|e| e.to_string())?;
+  let session = {
+    let mut sessions = state
+      .sessions
+      .lock()
+      .map_err(|_| "lsp sessions lock poisoned".to_string())?;
+    sessions.remove(&id)
+  };
+  let Some(session) = session else {
+    return Ok(());
+  };
+  let mut child = session
+    .child
+    .lock()
+    .map_err(|_| "lsp child lock poisoned".to_string())?;
+  child.kill().ok();
+  Ok(())
+}
+
+#[tauri::command]
+fn lsp_list(state: State<'_, Arc<LspState>>) -> CommandResult<Vec<LspSessionInfo>> {
+  let sessions = state
+    .sessions
+    .lock()
+    .map_err(|_| "lsp sessions lock poisoned".to_string())?;
+  Ok(sessions.values().map(|s| s.info.clone()).collect())
+}
+
 fn main() {
   let roots = Arc::new(RwLock::new(WebRoots::default()));
   let server = Arc::new(start_local_server(roots.clone()).expect("start local server"));
@@ -833,21 +1846,48 @@ fn main() {
     sessions: Mutex::new(HashMap::new()),
   });
 
+  let watcher_state = Arc::new(WatcherState {
+    watchers: Mutex::new(HashMap::new()),
+  });
+
+  let job_state = Arc::new(JobState {
+    jobs: Mutex::new(HashMap::new()),
+    run_dirs: Mutex::new(HashMap::new()),
+  });
+
+  let lsp_state = Arc::new(LspState {
+    sessions: Mutex::new(HashMap::new()),
+  });
+
   tauri::Builder::default()
     .manage(server)
     .manage(pty_state)
+    .manage(watcher_state)
+    .manage(job_state)
+    .manage(lsp_state)
     .invoke_handler(tauri::generate_handler![
       get_server_info,
       set_server_roots,
       detect_project,
       runs_list,
       run_artifacts,
+      watch_project,
+      unwatch_project,
       job_start,
+      job_tail,
+      job_kill,
+      job_list,
+      job_status,
       pty_create,
       pty_write,
       pty_resize,
       pty_kill,
       pty_list,
+      pty_snapshot,
+      lsp_start,
+      lsp_send,
+      lsp_stop,
+      lsp_list,
     ])
     .run(tauri::generate_context!())
     .expect("error while running Glia Fab Desktop");